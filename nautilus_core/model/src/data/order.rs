@@ -0,0 +1,47 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A single resting order as tracked by an L3 (market-by-order) book.
+
+use crate::{
+    enums::OrderSide,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// A resting order keyed by its venue-assigned `order_id`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BookOrder {
+    /// The side the order rests on.
+    pub side: OrderSide,
+    /// The order's limit price.
+    pub price: Price,
+    /// The order's remaining size.
+    pub size: Quantity,
+    /// The venue-assigned order identifier.
+    pub order_id: u64,
+}
+
+impl BookOrder {
+    /// Creates a new [`BookOrder`].
+    #[must_use]
+    pub const fn new(side: OrderSide, price: Price, size: Quantity, order_id: u64) -> Self {
+        Self {
+            side,
+            price,
+            size,
+            order_id,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Strongly-typed identifiers.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+    sync::{Mutex, OnceLock},
+};
+
+/// An instrument identifier, e.g. `"AAPL.XNAS"`.
+///
+/// Values are interned on construction so `InstrumentId` stays a cheap `Copy` handle, matching how
+/// it is threaded by value through [`DatabentoMboReplay`](crate::orderbook::replay::DatabentoMboReplay)
+/// and the SQLite sink/source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstrumentId(&'static str);
+
+impl InstrumentId {
+    /// Returns the identifier's string value.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl From<&str> for InstrumentId {
+    fn from(value: &str) -> Self {
+        Self(intern(value))
+    }
+}
+
+impl Display for InstrumentId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Returns a `'static` reference to `value`, reusing a prior allocation for the same string.
+fn intern(value: &str) -> &'static str {
+    static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let interned = INTERNED.get_or_init(|| Mutex::new(HashSet::new()));
+    let mut interned = interned.lock().unwrap();
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interns_equal_strings_to_the_same_value() {
+        let a = InstrumentId::from("AAPL.XNAS");
+        let b = InstrumentId::from("AAPL.XNAS");
+        assert_eq!(a, b);
+        assert_eq!(a.to_string(), "AAPL.XNAS");
+    }
+}
@@ -0,0 +1,216 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A reconstructed limit order book, keyed by individual resting orders (L3/MBO).
+//!
+//! Bids and asks are each kept as a price-ordered ladder of order-id-keyed levels, so the best
+//! price on either side is always the ladder's first/last key and a level's aggregate size is the
+//! sum of its resting orders.
+
+use std::collections::{BTreeMap, HashMap};
+
+use nautilus_core::nanos::UnixNanos;
+
+use crate::{
+    data::order::BookOrder,
+    enums::{BookType, OrderSide},
+    identifiers::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// A price-ordered ladder of resting orders, keyed by `order_id` within each price level.
+type Ladder = BTreeMap<Price, BTreeMap<u64, Quantity>>;
+
+/// A reconstructed order book for a single instrument.
+pub struct OrderBook {
+    instrument_id: InstrumentId,
+    book_type: BookType,
+    bids: Ladder,
+    asks: Ladder,
+    orders: HashMap<u64, (OrderSide, Price)>,
+}
+
+impl OrderBook {
+    /// Creates a new, empty [`OrderBook`] for `instrument_id`.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType) -> Self {
+        Self {
+            instrument_id,
+            book_type,
+            bids: Ladder::new(),
+            asks: Ladder::new(),
+            orders: HashMap::new(),
+        }
+    }
+
+    /// Returns the book's instrument identifier.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the book's configured granularity.
+    #[must_use]
+    pub const fn book_type(&self) -> BookType {
+        self.book_type
+    }
+
+    /// Removes every resting order, as on a Databento `R` (clear) record.
+    pub fn clear(&mut self, _sequence: u64, _ts_event: UnixNanos) {
+        self.bids.clear();
+        self.asks.clear();
+        self.orders.clear();
+    }
+
+    /// Adds a new resting order, as on a Databento `A` (add) record.
+    pub fn add(&mut self, order: BookOrder, _flags: u8, _sequence: u64, _ts_event: UnixNanos) {
+        self.insert(order);
+    }
+
+    /// Replaces a resting order's price/size, as on a Databento `M` (modify) record.
+    pub fn update(&mut self, order: BookOrder, _flags: u8, _sequence: u64, _ts_event: UnixNanos) {
+        self.remove(order.order_id);
+        self.insert(order);
+    }
+
+    /// Removes a resting order, as on a Databento `C`/`D` (cancel/delete) record.
+    pub fn delete(&mut self, order: BookOrder, _flags: u8, _sequence: u64, _ts_event: UnixNanos) {
+        self.remove(order.order_id);
+    }
+
+    /// Returns the highest resting bid price, if any.
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.bids.keys().next_back().copied()
+    }
+
+    /// Returns the lowest resting ask price, if any.
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.asks.keys().next().copied()
+    }
+
+    /// Returns the aggregate size resting at the best bid price, if any.
+    #[must_use]
+    pub fn best_bid_size(&self) -> Option<Quantity> {
+        self.bids.values().next_back().map(level_size)
+    }
+
+    /// Returns the aggregate size resting at the best ask price, if any.
+    #[must_use]
+    pub fn best_ask_size(&self) -> Option<Quantity> {
+        self.asks.values().next().map(level_size)
+    }
+
+    fn ladder_mut(&mut self, side: OrderSide) -> &mut Ladder {
+        match side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+        }
+    }
+
+    fn insert(&mut self, order: BookOrder) {
+        let side = order.side;
+        self.ladder_mut(side)
+            .entry(order.price)
+            .or_default()
+            .insert(order.order_id, order.size);
+        self.orders.insert(order.order_id, (side, order.price));
+    }
+
+    fn remove(&mut self, order_id: u64) {
+        let Some((side, price)) = self.orders.remove(&order_id) else {
+            return;
+        };
+        let ladder = self.ladder_mut(side);
+        if let Some(level) = ladder.get_mut(&price) {
+            level.remove(&order_id);
+            if level.is_empty() {
+                ladder.remove(&price);
+            }
+        }
+    }
+}
+
+/// Sums a price level's resting order sizes into a single aggregate [`Quantity`].
+fn level_size(level: &BTreeMap<u64, Quantity>) -> Quantity {
+    let precision = level.values().next().map_or(0, Quantity::precision);
+    let total: f64 = level.values().map(Quantity::as_f64).sum();
+    Quantity::new(total, precision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(side: OrderSide, price: i64, size: f64, order_id: u64) -> BookOrder {
+        BookOrder::new(
+            side,
+            Price::from_raw(price, 9),
+            Quantity::new(size, 0),
+            order_id,
+        )
+    }
+
+    #[test]
+    fn add_exposes_the_best_price_and_size_per_side() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let nanos = UnixNanos::from(0);
+
+        book.add(order(OrderSide::Buy, 1_000_000_000, 10.0, 1), 0, 1, nanos);
+        book.add(order(OrderSide::Buy, 1_010_000_000, 5.0, 2), 0, 2, nanos);
+        book.add(order(OrderSide::Sell, 1_020_000_000, 7.0, 3), 0, 3, nanos);
+
+        assert_eq!(book.best_bid_price(), Some(Price::from_raw(1_010_000_000, 9)));
+        assert_eq!(book.best_bid_size(), Some(Quantity::new(5.0, 0)));
+        assert_eq!(book.best_ask_price(), Some(Price::from_raw(1_020_000_000, 9)));
+        assert_eq!(book.best_ask_size(), Some(Quantity::new(7.0, 0)));
+    }
+
+    #[test]
+    fn update_replaces_an_order_in_place() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let nanos = UnixNanos::from(0);
+
+        book.add(order(OrderSide::Buy, 1_000_000_000, 10.0, 1), 0, 1, nanos);
+        book.update(order(OrderSide::Buy, 1_000_000_000, 3.0, 1), 0, 2, nanos);
+
+        assert_eq!(book.best_bid_size(), Some(Quantity::new(3.0, 0)));
+    }
+
+    #[test]
+    fn delete_removes_the_order_and_empties_the_level() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let nanos = UnixNanos::from(0);
+
+        book.add(order(OrderSide::Buy, 1_000_000_000, 10.0, 1), 0, 1, nanos);
+        book.delete(order(OrderSide::Buy, 1_000_000_000, 10.0, 1), 0, 2, nanos);
+
+        assert_eq!(book.best_bid_price(), None);
+    }
+
+    #[test]
+    fn clear_empties_both_sides() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let nanos = UnixNanos::from(0);
+
+        book.add(order(OrderSide::Buy, 1_000_000_000, 10.0, 1), 0, 1, nanos);
+        book.add(order(OrderSide::Sell, 1_020_000_000, 7.0, 2), 0, 2, nanos);
+        book.clear(3, nanos);
+
+        assert_eq!(book.best_bid_price(), None);
+        assert_eq!(book.best_ask_price(), None);
+    }
+}
@@ -0,0 +1,212 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A native decoder for Databento's binary encoding (DBN).
+//!
+//! Integer fields are assembled byte-by-byte through the [`Loadable`] trait so the reader behaves
+//! identically regardless of the host's byte order. Records are decoded into [`MboRecord`] values
+//! that the [`DatabentoMboReplay`](crate::orderbook::replay::DatabentoMboReplay) engine can apply.
+
+use nautilus_core::nanos::UnixNanos;
+
+use crate::orderbook::replay::MboRecord;
+
+/// The byte order of a serialized integer field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// The fixed size in bytes of a DBN record header.
+pub(crate) const HEADER_LEN: usize = 16;
+/// The `rtype` discriminant for market-by-order (MBO) messages.
+pub(crate) const RTYPE_MBO: u8 = 0xA0;
+/// The total size in bytes of an MBO record (header + body).
+pub(crate) const MBO_RECORD_LEN: usize = 56;
+/// DBN encodes `RecordHeader::length` in units of 32-bit words.
+pub(crate) const LENGTH_UNIT: usize = 4;
+
+/// Byte-addressable zero-copy integer reads over a borrowed buffer.
+///
+/// Each accessor assembles the result one byte at a time — for [`Endian::Little`] the `i`-th byte
+/// contributes `buf[off + i] << (8 * i)`, for [`Endian::Big`] the bytes are shifted in reverse —
+/// so decoding never depends on the host architecture's native endianness.
+pub trait Loadable {
+    /// Reads a little/big-endian `u32` starting at `offset`.
+    fn load_u32(&self, offset: usize, endian: Endian) -> anyhow::Result<u32>;
+    /// Reads a little/big-endian `u64` starting at `offset`.
+    fn load_u64(&self, offset: usize, endian: Endian) -> anyhow::Result<u64>;
+    /// Reads a little/big-endian `i64` starting at `offset`.
+    fn load_i64(&self, offset: usize, endian: Endian) -> anyhow::Result<i64>;
+}
+
+impl Loadable for [u8] {
+    fn load_u32(&self, offset: usize, endian: Endian) -> anyhow::Result<u32> {
+        let bytes = slice_at(self, offset, 4)?;
+        let mut acc: u32 = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            let shift = match endian {
+                Endian::Little => 8 * i,
+                Endian::Big => 8 * (3 - i),
+            };
+            acc |= u32::from(byte) << shift;
+        }
+        Ok(acc)
+    }
+
+    fn load_u64(&self, offset: usize, endian: Endian) -> anyhow::Result<u64> {
+        let bytes = slice_at(self, offset, 8)?;
+        let mut acc: u64 = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            let shift = match endian {
+                Endian::Little => 8 * i,
+                Endian::Big => 8 * (7 - i),
+            };
+            acc |= u64::from(byte) << shift;
+        }
+        Ok(acc)
+    }
+
+    fn load_i64(&self, offset: usize, endian: Endian) -> anyhow::Result<i64> {
+        self.load_u64(offset, endian).map(|v| v as i64)
+    }
+}
+
+fn slice_at(buf: &[u8], offset: usize, len: usize) -> anyhow::Result<&[u8]> {
+    buf.get(offset..offset + len)
+        .ok_or_else(|| anyhow::anyhow!("truncated field: need {len} bytes at offset {offset}"))
+}
+
+/// Decodes every complete MBO record in `buf`, stopping cleanly at a partial trailing record.
+///
+/// DBN is written in the producer's native byte order; pass the matching [`Endian`]. Any record
+/// with a non-MBO `rtype` is skipped by its declared length.
+///
+/// # Errors
+///
+/// Returns an error if a record's declared length does not match the size expected for its
+/// `rtype`, or if a field read runs past a record boundary.
+pub fn decode_mbo_records(buf: &[u8], endian: Endian) -> anyhow::Result<Vec<MboRecord>> {
+    let mut records = Vec::new();
+    let mut pos = 0;
+
+    while pos + HEADER_LEN <= buf.len() {
+        // `length` is expressed in 32-bit words and covers the header and body.
+        let declared_len = buf[pos] as usize * LENGTH_UNIT;
+        let rtype = buf[pos + 1];
+
+        if declared_len == 0 || pos + declared_len > buf.len() {
+            // Partial trailing record — stop without erroring.
+            break;
+        }
+
+        if rtype == RTYPE_MBO {
+            anyhow::ensure!(
+                declared_len == MBO_RECORD_LEN,
+                "MBO record length {declared_len} does not match expected {MBO_RECORD_LEN}"
+            );
+            records.push(decode_mbo(&buf[pos..pos + declared_len], endian)?);
+        }
+
+        pos += declared_len;
+    }
+
+    Ok(records)
+}
+
+/// Decodes a single MBO record whose header starts at the beginning of `rec`.
+pub(crate) fn decode_mbo(rec: &[u8], endian: Endian) -> anyhow::Result<MboRecord> {
+    // Header: length(u8), rtype(u8), publisher_id(u16), instrument_id(u32), ts_event(u64).
+    let ts_event = rec.load_u64(8, endian)?;
+
+    // Body begins immediately after the 16-byte header.
+    let order_id = rec.load_u64(HEADER_LEN, endian)?;
+    let price = rec.load_i64(HEADER_LEN + 8, endian)?;
+    let size = u64::from(rec.load_u32(HEADER_LEN + 16, endian)?);
+    let flags = rec[HEADER_LEN + 20];
+    let action = rec[HEADER_LEN + 22] as char;
+    let side = rec[HEADER_LEN + 23] as char;
+    let sequence = u64::from(rec.load_u32(HEADER_LEN + 36, endian)?);
+
+    Ok(MboRecord {
+        ts_event: UnixNanos::from(ts_event),
+        order_id,
+        price,
+        size,
+        action,
+        side,
+        flags,
+        sequence,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a 56-byte MBO record: a 16-byte header followed by the body fields at the offsets
+    /// [`decode_mbo`] reads, with every multi-byte field written little-endian.
+    fn build_mbo_record() -> Vec<u8> {
+        let mut rec = vec![0u8; MBO_RECORD_LEN];
+
+        // Header: length(u8, in 32-bit words), rtype(u8), publisher_id(u16), instrument_id(u32),
+        // ts_event(u64).
+        rec[0] = (MBO_RECORD_LEN / LENGTH_UNIT) as u8;
+        rec[1] = RTYPE_MBO;
+        rec[8..16].copy_from_slice(&1_700_000_000_000_000_000u64.to_le_bytes());
+
+        // Body.
+        rec[HEADER_LEN..HEADER_LEN + 8].copy_from_slice(&42u64.to_le_bytes());
+        rec[HEADER_LEN + 8..HEADER_LEN + 16].copy_from_slice(&1_000_000_000i64.to_le_bytes());
+        rec[HEADER_LEN + 16..HEADER_LEN + 20].copy_from_slice(&10u32.to_le_bytes());
+        rec[HEADER_LEN + 20] = 0; // flags
+        rec[HEADER_LEN + 21] = 0; // channel_id
+        rec[HEADER_LEN + 22] = b'A'; // action
+        rec[HEADER_LEN + 23] = b'B'; // side
+        rec[HEADER_LEN + 24..HEADER_LEN + 32].copy_from_slice(&1_700_000_000_000_000_001u64.to_le_bytes());
+        rec[HEADER_LEN + 32..HEADER_LEN + 36].copy_from_slice(&5u32.to_le_bytes());
+        rec[HEADER_LEN + 36..HEADER_LEN + 40].copy_from_slice(&7u32.to_le_bytes());
+
+        rec
+    }
+
+    #[test]
+    fn decode_mbo_round_trips_a_hand_built_record() {
+        let rec = build_mbo_record();
+
+        let decoded = decode_mbo(&rec, Endian::Little).unwrap();
+
+        assert_eq!(decoded.order_id, 42);
+        assert_eq!(decoded.price, 1_000_000_000);
+        assert_eq!(decoded.size, 10);
+        assert_eq!(decoded.action, 'A');
+        assert_eq!(decoded.side, 'B');
+        assert_eq!(decoded.flags, 0);
+        assert_eq!(decoded.sequence, 7);
+    }
+
+    #[test]
+    fn decode_mbo_records_decodes_a_single_record_buffer() {
+        let rec = build_mbo_record();
+
+        let records = decode_mbo_records(&rec, Endian::Little).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].sequence, 7);
+    }
+}
@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An optional SQLite sink and source for reconstructed order-book deltas.
+//!
+//! After a first parse of a CSV/DBN file, normalized deltas are written into a `book_deltas` table
+//! keyed on `(instrument_id, sequence)`. Subsequent runs skip the file parse entirely and feed the
+//! [`DatabentoMboReplay`] engine directly from a sequence-ordered query, optionally restricted to a
+//! `ts_event` slice for partial replay.
+
+use nautilus_core::nanos::UnixNanos;
+use rusqlite::{params, Connection};
+
+use crate::{
+    identifiers::InstrumentId,
+    orderbook::{book::OrderBook, replay::DatabentoMboReplay, replay::MboRecord},
+};
+
+/// Creates the `book_deltas` table and its supporting index if they do not already exist.
+///
+/// The primary key on `(instrument_id, sequence)` de-duplicates re-ingested records, and the
+/// `ts_event` index supports time-bounded slice queries.
+///
+/// # Errors
+///
+/// Returns an error if the statements cannot be executed.
+pub fn create_schema(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS book_deltas (
+            instrument_id TEXT    NOT NULL,
+            ts_event      INTEGER NOT NULL,
+            sequence      INTEGER NOT NULL,
+            action        TEXT    NOT NULL,
+            side          TEXT    NOT NULL,
+            order_id      INTEGER NOT NULL,
+            price         INTEGER NOT NULL,
+            size          INTEGER NOT NULL,
+            flags         INTEGER NOT NULL,
+            PRIMARY KEY (instrument_id, sequence)
+        );
+        CREATE INDEX IF NOT EXISTS ix_book_deltas_ts_event
+            ON book_deltas (instrument_id, ts_event);",
+    )?;
+    Ok(())
+}
+
+/// Writes `records` for `instrument_id` into `book_deltas` in a single transaction.
+///
+/// Existing rows with the same `(instrument_id, sequence)` are replaced, so re-ingesting a file is
+/// idempotent.
+///
+/// # Errors
+///
+/// Returns an error if the transaction or any insert fails.
+pub fn insert_records(
+    conn: &mut Connection,
+    instrument_id: InstrumentId,
+    records: &[MboRecord],
+) -> anyhow::Result<()> {
+    let instrument_id = instrument_id.to_string();
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT OR REPLACE INTO book_deltas
+                (instrument_id, ts_event, sequence, action, side, order_id, price, size, flags)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )?;
+        for r in records {
+            stmt.execute(params![
+                instrument_id,
+                r.ts_event.as_u64() as i64,
+                r.sequence as i64,
+                r.action.to_string(),
+                r.side.to_string(),
+                r.order_id as i64,
+                r.price,
+                r.size as i64,
+                i64::from(r.flags),
+            ])?;
+        }
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Returns the stored records for `instrument_id` ordered by `sequence`.
+///
+/// Every matching row is read into the returned `Vec` before it is returned; rows are not yielded
+/// lazily.
+///
+/// When `start` and/or `end` are provided the query is restricted to the half-open `ts_event`
+/// slice `[start, end)` for partial replay.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or a row cannot be decoded.
+pub fn stream_records(
+    conn: &Connection,
+    instrument_id: InstrumentId,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<Vec<MboRecord>> {
+    let start = start.map_or(i64::MIN, |t| t.as_u64() as i64);
+    let end = end.map_or(i64::MAX, |t| t.as_u64() as i64);
+
+    let mut stmt = conn.prepare(
+        "SELECT ts_event, order_id, price, size, action, side, flags, sequence
+           FROM book_deltas
+          WHERE instrument_id = ?1 AND ts_event >= ?2 AND ts_event < ?3
+          ORDER BY sequence ASC",
+    )?;
+
+    let rows = stmt.query_map(
+        params![instrument_id.to_string(), start, end],
+        |row| {
+            let action: String = row.get(4)?;
+            let side: String = row.get(5)?;
+            let action = action.chars().next().ok_or_else(|| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    4,
+                    rusqlite::types::Type::Text,
+                    "empty MBO action".into(),
+                )
+            })?;
+            let side = side.chars().next().ok_or_else(|| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    5,
+                    rusqlite::types::Type::Text,
+                    "empty MBO side".into(),
+                )
+            })?;
+            Ok(MboRecord {
+                ts_event: UnixNanos::from(row.get::<_, i64>(0)? as u64),
+                order_id: row.get::<_, i64>(1)? as u64,
+                price: row.get(2)?,
+                size: row.get::<_, i64>(3)? as u64,
+                action,
+                side,
+                flags: row.get::<_, i64>(6)? as u8,
+                sequence: row.get::<_, i64>(7)? as u64,
+            })
+        },
+    )?;
+
+    let mut records = Vec::new();
+    for row in rows {
+        records.push(row?);
+    }
+    Ok(records)
+}
+
+/// Reconstructs an [`OrderBook`] for `instrument_id` directly from the stored deltas.
+///
+/// `start`/`end` bound the replayed `ts_event` slice as in [`stream_records`], letting a caller
+/// re-replay a single session without re-parsing the source file.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or a record violates the replay sequence invariant.
+pub fn replay_from_sqlite(
+    conn: &Connection,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<OrderBook> {
+    let records = stream_records(conn, instrument_id, start, end)?;
+    let mut replay = DatabentoMboReplay::new(instrument_id, price_precision, size_precision);
+    replay.replay(&records)?;
+    Ok(replay.into_book())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record(sequence: u64) -> MboRecord {
+        MboRecord {
+            ts_event: UnixNanos::from(1_700_000_000_000_000_000u64 + sequence),
+            order_id: 1,
+            price: 1_000_000_000,
+            size: 10,
+            action: 'A',
+            side: 'B',
+            flags: 0,
+            sequence,
+        }
+    }
+
+    #[test]
+    fn insert_then_stream_round_trips_records() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        create_schema(&conn).unwrap();
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let records = vec![sample_record(1), sample_record(2)];
+
+        insert_records(&mut conn, instrument_id, &records).unwrap();
+        let streamed = stream_records(&conn, instrument_id, None, None).unwrap();
+
+        assert_eq!(streamed.len(), 2);
+        assert_eq!(streamed[0].sequence, 1);
+        assert_eq!(streamed[1].sequence, 2);
+        assert_eq!(streamed[0].action, 'A');
+        assert_eq!(streamed[0].side, 'B');
+    }
+
+    #[test]
+    fn stream_records_rejects_a_corrupt_empty_action() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_schema(&conn).unwrap();
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        conn.execute(
+            "INSERT INTO book_deltas
+                (instrument_id, ts_event, sequence, action, side, order_id, price, size, flags)
+             VALUES (?1, 0, 1, '', 'B', 1, 1000000000, 10, 0)",
+            params![instrument_id.to_string()],
+        )
+        .unwrap();
+
+        let result = stream_records(&conn, instrument_id, None, None);
+
+        assert!(result.is_err());
+    }
+}
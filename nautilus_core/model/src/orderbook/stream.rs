@@ -0,0 +1,288 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A streaming, memory-mapped reader for large DBN MBO files.
+//!
+//! The reader memory-maps the file and yields decoded [`MboRecord`] events one at a time through an
+//! iterator, so reconstructing a book never materializes the whole dataset. Callers can replay an
+//! arbitrary byte slice via [`MboStreamReader::read_range`] (e.g. a single trading session) and can
+//! resume from a checkpointed byte offset via [`MboStreamReader::iter_from`].
+
+use std::{fs::File, path::Path};
+
+use memmap2::Mmap;
+
+use crate::orderbook::{
+    dbn::{decode_mbo, Endian, HEADER_LEN, LENGTH_UNIT, MBO_RECORD_LEN, RTYPE_MBO},
+    replay::MboRecord,
+};
+
+/// Default `buffer_size` in bytes (1 MiB) reported by [`MboStreamReader::buffer_size`].
+///
+/// Records are decoded directly from the mapping; the value does not size, bound or hint any read.
+pub const DEFAULT_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// The mapped bytes backing a [`MboStreamReader`].
+///
+/// `memmap2::Mmap::map` errors on a zero-length file, so an empty file is represented without a
+/// mapping at all rather than forcing every caller to special-case that `Mmap::map` call.
+enum MappedData {
+    Mapped(Mmap),
+    Empty,
+}
+
+impl MappedData {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Mapped(mmap) => mmap,
+            Self::Empty => &[],
+        }
+    }
+}
+
+/// A memory-mapped DBN file exposing streaming, range, and resumable MBO iteration.
+pub struct MboStreamReader {
+    data: MappedData,
+    endian: Endian,
+    buffer_size: usize,
+}
+
+impl MboStreamReader {
+    /// Memory-maps the DBN file at `path` for streaming decode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or mapped.
+    pub fn open(path: &Path, endian: Endian) -> anyhow::Result<Self> {
+        Self::with_buffer_size(path, endian, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Memory-maps the DBN file at `path`, recording `buffer_size` as the reader's configured size.
+    ///
+    /// `buffer_size` is stored and reported by [`Self::buffer_size`]; decoding does not depend on it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or mapped, or `buffer_size` is zero.
+    pub fn with_buffer_size(
+        path: &Path,
+        endian: Endian,
+        buffer_size: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(buffer_size > 0, "buffer_size must be non-zero");
+        let file = File::open(path)?;
+        // `Mmap::map` errors on a zero-length file on most platforms, so an empty file maps to no
+        // mapping at all rather than a (disallowed) zero-length one.
+        let data = if file.metadata()?.len() == 0 {
+            MappedData::Empty
+        } else {
+            // SAFETY: the mapping is read-only and lives as long as the reader holds the file.
+            MappedData::Mapped(unsafe { Mmap::map(&file)? })
+        };
+        Ok(Self {
+            data,
+            endian,
+            buffer_size,
+        })
+    }
+
+    /// Returns the configured buffer size in bytes.
+    #[must_use]
+    pub const fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the total mapped length in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.as_slice().len()
+    }
+
+    /// Returns `true` if the mapped file is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.as_slice().is_empty()
+    }
+
+    /// Streams every MBO record from the start of the file.
+    #[must_use]
+    pub fn iter(&self) -> MboStreamIter<'_> {
+        self.iter_from(0)
+    }
+
+    /// Streams MBO records starting from byte `offset`, for resuming a checkpointed replay.
+    ///
+    /// `offset` must fall on a record boundary (e.g. a value previously returned by
+    /// [`MboStreamIter::offset`]); decoding from the middle of a record yields undefined records.
+    #[must_use]
+    pub fn iter_from(&self, offset: usize) -> MboStreamIter<'_> {
+        let buf = self.data.as_slice();
+        let end = buf.len();
+        MboStreamIter {
+            buf,
+            pos: offset.min(end),
+            end,
+            endian: self.endian,
+        }
+    }
+
+    /// Streams MBO records within the byte range `[start_offset, start_offset + len)`.
+    ///
+    /// This allows replaying an arbitrary slice (such as a single trading session) without
+    /// touching the rest of the mapping. The window is clamped to the file bounds.
+    #[must_use]
+    pub fn read_range(&self, start_offset: usize, len: usize) -> MboStreamIter<'_> {
+        let buf = self.data.as_slice();
+        let total = buf.len();
+        let start = start_offset.min(total);
+        let end = start.saturating_add(len).min(total);
+        MboStreamIter {
+            buf,
+            pos: start,
+            end,
+            endian: self.endian,
+        }
+    }
+}
+
+/// A bounded iterator over decoded MBO records within a mapped region.
+pub struct MboStreamIter<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    end: usize,
+    endian: Endian,
+}
+
+impl MboStreamIter<'_> {
+    /// Returns the current byte offset, suitable for checkpointing a replay.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+impl Iterator for MboStreamIter<'_> {
+    type Item = anyhow::Result<MboRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos + HEADER_LEN <= self.end {
+            let declared_len = self.buf[self.pos] as usize * LENGTH_UNIT;
+            let rtype = self.buf[self.pos + 1];
+
+            if declared_len == 0 || self.pos + declared_len > self.end {
+                // Partial trailing record within the window — stop cleanly, leaving `pos` on the
+                // record's start so `offset()` stays a valid resume point.
+                return None;
+            }
+
+            let rec = &self.buf[self.pos..self.pos + declared_len];
+            self.pos += declared_len;
+
+            if rtype == RTYPE_MBO {
+                if declared_len != MBO_RECORD_LEN {
+                    return Some(Err(anyhow::anyhow!(
+                        "MBO record length {declared_len} does not match expected {MBO_RECORD_LEN}"
+                    )));
+                }
+                return Some(decode_mbo(rec, self.endian));
+            }
+            // Non-MBO record: skip and continue scanning.
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    /// Builds a buffer holding `count` consecutive MBO records with sequence `1..=count`.
+    fn build_mbo_buffer(count: u32) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(count as usize * MBO_RECORD_LEN);
+        for sequence in 1..=count {
+            let mut rec = vec![0u8; MBO_RECORD_LEN];
+            rec[0] = (MBO_RECORD_LEN / LENGTH_UNIT) as u8;
+            rec[1] = RTYPE_MBO;
+            rec[HEADER_LEN + 22] = b'A';
+            rec[HEADER_LEN + 23] = b'B';
+            rec[HEADER_LEN + 36..HEADER_LEN + 40].copy_from_slice(&sequence.to_le_bytes());
+            buf.extend_from_slice(&rec);
+        }
+        buf
+    }
+
+    fn write_temp_file(bytes: &[u8]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(bytes).unwrap();
+        file.flush().unwrap();
+        file
+    }
+
+    #[test]
+    fn iter_streams_every_record_in_order() {
+        let file = write_temp_file(&build_mbo_buffer(3));
+        let reader = MboStreamReader::open(file.path(), Endian::Little).unwrap();
+
+        let sequences: Vec<u64> = reader.iter().map(|r| r.unwrap().sequence).collect();
+
+        assert_eq!(sequences, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_from_resumes_at_a_checkpointed_offset() {
+        let file = write_temp_file(&build_mbo_buffer(3));
+        let reader = MboStreamReader::open(file.path(), Endian::Little).unwrap();
+
+        let mut iter = reader.iter();
+        let first = iter.next().unwrap().unwrap();
+        assert_eq!(first.sequence, 1);
+        let resume_offset = iter.offset();
+
+        let resumed: Vec<u64> = reader
+            .iter_from(resume_offset)
+            .map(|r| r.unwrap().sequence)
+            .collect();
+
+        assert_eq!(resumed, vec![2, 3]);
+    }
+
+    #[test]
+    fn open_accepts_a_zero_length_file() {
+        let file = write_temp_file(&[]);
+
+        let reader = MboStreamReader::open(file.path(), Endian::Little).unwrap();
+
+        assert!(reader.is_empty());
+        assert_eq!(reader.len(), 0);
+        assert_eq!(reader.iter().count(), 0);
+    }
+
+    #[test]
+    fn read_range_bounds_iteration_to_the_requested_window() {
+        let file = write_temp_file(&build_mbo_buffer(3));
+        let reader = MboStreamReader::open(file.path(), Endian::Little).unwrap();
+
+        let windowed: Vec<u64> = reader
+            .read_range(MBO_RECORD_LEN, MBO_RECORD_LEN)
+            .map(|r| r.unwrap().sequence)
+            .collect();
+
+        assert_eq!(windowed, vec![2]);
+    }
+}
@@ -0,0 +1,75 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A fixed-point price.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A price represented as fixed-point raw ticks at a given decimal `precision`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Price {
+    raw: i64,
+    precision: u8,
+}
+
+impl Price {
+    /// Creates a [`Price`] from `raw` fixed-point ticks at `precision` fractional digits.
+    #[must_use]
+    pub const fn from_raw(raw: i64, precision: u8) -> Self {
+        Self { raw, precision }
+    }
+
+    /// Returns the raw fixed-point ticks.
+    #[must_use]
+    pub const fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the decimal precision.
+    #[must_use]
+    pub const fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns the price as a floating-point value.
+    #[must_use]
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / 10f64.powi(i32::from(self.precision))
+    }
+}
+
+impl Display for Price {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", usize::from(self.precision), self.as_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_raw_ticks() {
+        let lower = Price::from_raw(1_000_000_000, 9);
+        let higher = Price::from_raw(2_000_000_000, 9);
+        assert!(lower < higher);
+    }
+
+    #[test]
+    fn as_f64_applies_the_precision() {
+        let price = Price::from_raw(1_500_000_000, 9);
+        assert!((price.as_f64() - 1.5).abs() < 1e-9);
+    }
+}
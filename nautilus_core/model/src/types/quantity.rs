@@ -0,0 +1,68 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An order or trade quantity.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A size/quantity value at a given decimal `precision`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    precision: u8,
+}
+
+impl Quantity {
+    /// Creates a [`Quantity`] of `value` at `precision` fractional digits.
+    #[must_use]
+    pub const fn new(value: f64, precision: u8) -> Self {
+        Self { value, precision }
+    }
+
+    /// Returns the quantity as a floating-point value.
+    #[must_use]
+    pub const fn as_f64(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the decimal precision.
+    #[must_use]
+    pub const fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns `true` if the quantity is strictly greater than zero.
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        self.value > 0.0
+    }
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", usize::from(self.precision), self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_positive_reflects_the_value() {
+        assert!(Quantity::new(10.0, 0).is_positive());
+        assert!(!Quantity::new(0.0, 0).is_positive());
+    }
+}
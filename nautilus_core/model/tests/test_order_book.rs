@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::{enums::BookType, identifiers::InstrumentId, orderbook::book::OrderBook};
+use nautilus_model::{identifiers::InstrumentId, orderbook::replay::load_databento_mbo_csv};
 use nautilus_test_kit::{
     common::{get_project_testdata_path, get_testdata_large_checksums_filepath},
     files::ensure_file_exists_or_download_http,
@@ -30,10 +30,12 @@ pub fn test_order_book_databento_mbo_nasdaq() {
     ensure_file_exists_or_download_http(&filepath, url, Some(&checksums)).unwrap();
 
     let instrument_id = InstrumentId::from("AAPL.XNAS");
-    let _ = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let book = load_databento_mbo_csv(&filepath, instrument_id, 9, 0).unwrap();
 
-    // assert_eq!(book.best_bid_price().unwrap(), price);
-    // assert_eq!(book.best_ask_price().unwrap(), price);
-    // assert_eq!(book.best_bid_size().unwrap(), size);
-    // assert_eq!(book.best_ask_size().unwrap(), size);
+    // After replaying the full session the book exposes a crossed-free top of book.
+    let best_bid = book.best_bid_price().unwrap();
+    let best_ask = book.best_ask_price().unwrap();
+    assert!(best_bid < best_ask);
+    assert!(book.best_bid_size().unwrap().is_positive());
+    assert!(book.best_ask_size().unwrap().is_positive());
 }
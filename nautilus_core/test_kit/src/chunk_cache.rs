@@ -0,0 +1,315 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A content-addressed chunk cache for large test-data fixtures.
+//!
+//! Files are split with content-defined chunking (a gear-based rolling hash), and each chunk is
+//! stored under its SHA-256 digest in a local content-addressable store (CAS). A file is described
+//! by a manifest of chunk digests, and reconstruction only needs to fetch the chunks that are not
+//! already present locally — giving deduplicated, resumable provisioning across the `large/`
+//! fixtures.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// Minimum chunk size (256 KiB); boundaries below this are suppressed.
+const MIN_CHUNK: usize = 256 * 1024;
+/// Maximum chunk size (4 MiB); a boundary is forced once reached.
+const MAX_CHUNK: usize = 4 * 1024 * 1024;
+/// Boundary mask tuned for a ~1 MiB average chunk size (20 bits).
+const MASK: u64 = (1 << 20) - 1;
+
+/// Gear table mapping each byte value to a pseudo-random 64-bit word.
+static GEAR: [u64; 256] = build_gear();
+
+/// Builds the gear table deterministically from a fixed seed using SplitMix64.
+const fn build_gear() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut i = 0;
+    while i < 256 {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+}
+
+/// The SHA-256 digests of a file's chunks, in file order.
+pub type Manifest = Vec<String>;
+
+/// Splits `data` into content-defined chunk ranges `[start, end)`.
+///
+/// A boundary is declared whenever the rolling fingerprint `h = (h << 1) + GEAR[byte]` satisfies
+/// `h & MASK == 0`, subject to the [`MIN_CHUNK`]/[`MAX_CHUNK`] clamps.
+#[must_use]
+pub fn chunk_ranges(data: &[u8]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut hash: u64 = 0;
+
+    for (i, &byte) in data.iter().enumerate() {
+        hash = (hash << 1).wrapping_add(GEAR[byte as usize]);
+        let len = i - start + 1;
+        if len < MIN_CHUNK {
+            continue;
+        }
+        if (hash & MASK == 0) || len >= MAX_CHUNK {
+            ranges.push((start, i + 1));
+            start = i + 1;
+            hash = 0;
+        }
+    }
+    if start < data.len() {
+        ranges.push((start, data.len()));
+    }
+    ranges
+}
+
+/// Returns the lowercase hex SHA-256 digest of `bytes`.
+#[must_use]
+pub fn digest_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// A local content-addressable store of file chunks.
+pub struct ChunkCache {
+    cas_dir: PathBuf,
+}
+
+impl ChunkCache {
+    /// Creates a cache rooted at `cas_dir`, creating the directory if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CAS directory cannot be created.
+    pub fn new(cas_dir: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let cas_dir = cas_dir.into();
+        fs::create_dir_all(&cas_dir)?;
+        Ok(Self { cas_dir })
+    }
+
+    /// Returns the on-disk path for a chunk with the given hex `digest`.
+    #[must_use]
+    fn chunk_path(&self, digest: &str) -> PathBuf {
+        // Fan out by the first two hex characters to keep directories small.
+        self.cas_dir.join(&digest[..2]).join(digest)
+    }
+
+    /// Returns `true` if a chunk with `digest` is already stored locally.
+    #[must_use]
+    pub fn has_chunk(&self, digest: &str) -> bool {
+        self.chunk_path(digest).is_file()
+    }
+
+    /// Stores `bytes` under its digest, returning that digest. Existing chunks are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk cannot be written.
+    pub fn put_chunk(&self, bytes: &[u8]) -> anyhow::Result<String> {
+        let digest = digest_hex(bytes);
+        let path = self.chunk_path(&digest);
+        if !path.is_file() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, bytes)?;
+        }
+        Ok(digest)
+    }
+
+    /// Reads the chunk stored under `digest`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk is absent or cannot be read.
+    pub fn get_chunk(&self, digest: &str) -> anyhow::Result<Vec<u8>> {
+        Ok(fs::read(self.chunk_path(digest))?)
+    }
+
+    /// Chunks the file at `filepath`, stores every chunk, and returns its manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a chunk cannot be written.
+    pub fn ingest_file(&self, filepath: &Path) -> anyhow::Result<Manifest> {
+        self.ingest_bytes(&fs::read(filepath)?)
+    }
+
+    /// Chunks `data`, stores every chunk, and returns its manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a chunk cannot be written.
+    pub fn ingest_bytes(&self, data: &[u8]) -> anyhow::Result<Manifest> {
+        let mut manifest = Manifest::new();
+        for (start, end) in chunk_ranges(data) {
+            manifest.push(self.put_chunk(&data[start..end])?);
+        }
+        Ok(manifest)
+    }
+
+    /// Returns the digests in `manifest` that are not yet present in the CAS.
+    #[must_use]
+    pub fn missing_chunks(&self, manifest: &Manifest) -> Vec<String> {
+        manifest
+            .iter()
+            .filter(|d| !self.has_chunk(d))
+            .cloned()
+            .collect()
+    }
+
+    /// Ensures every chunk in `manifest` is present, fetching absent chunks via `fetch`.
+    ///
+    /// `fetch` is called once per missing digest and must return that chunk's exact bytes; the
+    /// cache re-verifies the digest before storing so a corrupt fetch is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `fetch` fails or returns bytes whose digest does not match.
+    pub fn fetch_missing<F>(&self, manifest: &Manifest, mut fetch: F) -> anyhow::Result<()>
+    where
+        F: FnMut(&str) -> anyhow::Result<Vec<u8>>,
+    {
+        for digest in self.missing_chunks(manifest) {
+            // A digest repeated in the manifest is already stored after its first fetch.
+            if self.has_chunk(&digest) {
+                continue;
+            }
+            let bytes = fetch(&digest)?;
+            let actual = digest_hex(&bytes);
+            anyhow::ensure!(
+                actual == digest,
+                "fetched chunk digest mismatch: expected {digest}, got {actual}"
+            );
+            self.put_chunk(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Reconstructs the file described by `manifest` into `out`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a referenced chunk is missing or the output cannot be written.
+    pub fn reconstruct(&self, manifest: &Manifest, out: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = out.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut data = Vec::new();
+        for digest in manifest {
+            data.extend_from_slice(&self.get_chunk(digest)?);
+        }
+        fs::write(out, &data)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn ingest_then_reconstruct_round_trips_the_original_file() {
+        let dir = tempdir().unwrap();
+        let cache = ChunkCache::new(dir.path().join("cas")).unwrap();
+        let data = vec![7u8; MIN_CHUNK * 3];
+        let source = dir.path().join("source.bin");
+        fs::write(&source, &data).unwrap();
+
+        let manifest = cache.ingest_file(&source).unwrap();
+        assert!(cache.missing_chunks(&manifest).is_empty());
+
+        let out = dir.path().join("reconstructed.bin");
+        cache.reconstruct(&manifest, &out).unwrap();
+
+        assert_eq!(fs::read(&out).unwrap(), data);
+    }
+
+    #[test]
+    fn fetch_missing_only_invokes_fetch_for_absent_digests() {
+        let dir = tempdir().unwrap();
+        let cache = ChunkCache::new(dir.path().join("cas")).unwrap();
+
+        let cached_digest = cache.put_chunk(b"already local").unwrap();
+        let missing_bytes = b"needs fetching".to_vec();
+        let missing_digest = digest_hex(&missing_bytes);
+        let manifest = vec![cached_digest, missing_digest.clone()];
+
+        let mut fetched = Vec::new();
+        cache
+            .fetch_missing(&manifest, |digest| {
+                fetched.push(digest.to_string());
+                Ok(missing_bytes.clone())
+            })
+            .unwrap();
+
+        assert_eq!(fetched, vec![missing_digest.clone()]);
+        assert!(cache.has_chunk(&missing_digest));
+    }
+
+    #[test]
+    fn ingest_bytes_matches_ingest_file_for_the_same_content() {
+        let dir = tempdir().unwrap();
+        let cache = ChunkCache::new(dir.path().join("cas")).unwrap();
+        let data = vec![3u8; MIN_CHUNK * 2];
+        let source = dir.path().join("source.bin");
+        fs::write(&source, &data).unwrap();
+
+        let from_file = cache.ingest_file(&source).unwrap();
+        let from_bytes = cache.ingest_bytes(&data).unwrap();
+
+        assert_eq!(from_file, from_bytes);
+    }
+
+    #[test]
+    fn fetch_missing_rejects_a_digest_mismatch() {
+        let dir = tempdir().unwrap();
+        let cache = ChunkCache::new(dir.path().join("cas")).unwrap();
+        let manifest = vec![digest_hex(b"expected")];
+
+        let result = cache.fetch_missing(&manifest, |_| Ok(b"wrong bytes".to_vec()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn chunk_ranges_cover_the_whole_buffer_and_respect_min_max() {
+        let data = vec![0u8; MIN_CHUNK * 5];
+        let ranges = chunk_ranges(&data);
+
+        assert_eq!(ranges.first().unwrap().0, 0);
+        assert_eq!(ranges.last().unwrap().1, data.len());
+        for (start, end) in &ranges {
+            assert!(end - start <= MAX_CHUNK);
+        }
+        // Every chunk but the trailing one is at least `MIN_CHUNK` long.
+        for (start, end) in &ranges[..ranges.len() - 1] {
+            assert!(end - start >= MIN_CHUNK);
+        }
+    }
+}
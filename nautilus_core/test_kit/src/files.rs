@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fixture-file download helpers.
+//!
+//! [`ensure_file_exists_or_download_http`] downloads a fixture through the content-defined
+//! [`ChunkCache`]: the remote payload is chunked the same way as any other ingested file, and a
+//! manifest of its chunk digests is kept alongside the cache. On a later call for the same `url`
+//! (for example a fixture re-downloaded after `filepath` was cleaned up) the file is rebuilt from
+//! the local CAS without a download when every chunk in that manifest is present. If any chunk is
+//! missing, the whole body is downloaded again; individual chunks are not fetched on their own,
+//! and only chunks the CAS does not already hold are written to it.
+
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::chunk_cache::{digest_hex, ChunkCache, Manifest};
+
+/// Root directory for the on-disk chunk cache backing HTTP fixture downloads.
+fn cache_dir() -> PathBuf {
+    std::env::temp_dir().join("nautilus_test_data_cache")
+}
+
+/// Path of the persisted chunk manifest for `url`, one digest per line.
+fn manifest_path(url: &str) -> PathBuf {
+    cache_dir()
+        .join("manifests")
+        .join(format!("{}.manifest", digest_hex(url.as_bytes())))
+}
+
+/// Loads a previously persisted manifest, if any.
+fn load_manifest(path: &Path) -> anyhow::Result<Manifest> {
+    if !path.is_file() {
+        return Ok(Manifest::new());
+    }
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
+
+/// Persists `manifest` so a later download of the same URL can skip cached chunks.
+fn save_manifest(path: &Path, manifest: &Manifest) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, manifest.join("\n"))?;
+    Ok(())
+}
+
+/// Ensures the fixture file at `filepath` exists locally, downloading it from `url` if missing.
+///
+/// The downloaded bytes are split into content-defined chunks and stored in a local CAS keyed by
+/// chunk digest. If every chunk from a prior download of this `url` is already present locally,
+/// the file is reconstructed from the cache without touching the network at all; otherwise the
+/// body is fetched once and only the chunks the CAS doesn't already hold are written to it. When
+/// `checksums` is given, the reconstructed file's SHA-256 digest is verified against the entry
+/// there matching `filepath`'s file name.
+///
+/// # Errors
+///
+/// Returns an error if `url` cannot be downloaded, the cache or `filepath` cannot be written, or
+/// (when `checksums` is given) the reconstructed file's digest does not match the recorded checksum.
+pub fn ensure_file_exists_or_download_http(
+    filepath: &Path,
+    url: &str,
+    checksums: Option<&Path>,
+) -> anyhow::Result<()> {
+    if filepath.is_file() {
+        return Ok(());
+    }
+    if let Some(parent) = filepath.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let cache = ChunkCache::new(cache_dir())?;
+    let manifest_file = manifest_path(url);
+    let manifest = load_manifest(&manifest_file)?;
+
+    let manifest = if !manifest.is_empty() && cache.missing_chunks(&manifest).is_empty() {
+        manifest
+    } else {
+        let body = download(url)?;
+        let manifest = cache.ingest_bytes(&body)?;
+        save_manifest(&manifest_file, &manifest)?;
+        manifest
+    };
+    cache.reconstruct(&manifest, filepath)?;
+
+    if let Some(checksums) = checksums {
+        verify_checksum(filepath, checksums)?;
+    }
+    Ok(())
+}
+
+/// Downloads the full body at `url`.
+fn download(url: &str) -> anyhow::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    ureq::get(url)
+        .call()
+        .map_err(|e| anyhow::anyhow!("failed to download {url}: {e}"))?
+        .into_reader()
+        .read_to_end(&mut body)?;
+    Ok(body)
+}
+
+/// Verifies that `filepath`'s SHA-256 digest matches its entry in the `sha256sum`-style
+/// `checksums` file (lines of `<digest>  <filename>`), matched by file name.
+fn verify_checksum(filepath: &Path, checksums: &Path) -> anyhow::Result<()> {
+    let file_name = filepath
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("filepath has no file name: {}", filepath.display()))?
+        .to_string_lossy();
+
+    let expected = std::fs::read_to_string(checksums)?
+        .lines()
+        .find_map(|line| {
+            let (digest, name) = line.split_once(char::is_whitespace)?;
+            (name.trim() == file_name).then(|| digest.to_owned())
+        })
+        .ok_or_else(|| anyhow::anyhow!("no checksum entry for {file_name}"))?;
+
+    let actual = digest_hex(&std::fs::read(filepath)?);
+    anyhow::ensure!(
+        actual == expected,
+        "checksum mismatch for {file_name}: expected {expected}, got {actual}"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::chunk_cache::digest_hex;
+
+    #[test]
+    fn ensure_file_exists_or_download_http_is_a_no_op_when_the_file_is_already_present() {
+        let dir = tempdir().unwrap();
+        let filepath = dir.path().join("already_here.bin");
+        std::fs::write(&filepath, b"cached locally").unwrap();
+
+        // A URL that would error if actually requested proves the network path is never taken.
+        ensure_file_exists_or_download_http(&filepath, "not-a-url", None).unwrap();
+
+        assert_eq!(std::fs::read(&filepath).unwrap(), b"cached locally");
+    }
+
+    #[test]
+    fn ensure_file_exists_or_download_http_detects_a_checksum_mismatch() {
+        let dir = tempdir().unwrap();
+        let filepath = dir.path().join("data.bin");
+        std::fs::write(&filepath, b"wrong content").unwrap();
+        std::fs::remove_file(&filepath).unwrap();
+
+        // Pre-populate the manifest/CAS as if a previous run had already cached this URL, so the
+        // test stays offline.
+        let cache = ChunkCache::new(cache_dir()).unwrap();
+        let manifest = cache.ingest_bytes(b"wrong content").unwrap();
+        save_manifest(&manifest_path("file://offline-test"), &manifest).unwrap();
+
+        let checksums = dir.path().join("checksums.txt");
+        std::fs::write(
+            &checksums,
+            format!("{}  data.bin\n", digest_hex(b"expected content")),
+        )
+        .unwrap();
+
+        let result =
+            ensure_file_exists_or_download_http(&filepath, "file://offline-test", Some(&checksums));
+
+        assert!(result.is_err());
+    }
+}